@@ -32,8 +32,9 @@
 //! Something implementing `serde::Serialize` needs to be provided as context in
 //! order to render the template. Values are rendered using
 //! [upon](https://github.com/rossmacarthur/upon) e.g. `{{ value.field }}`.
-//! You cannot use  `GOLDIE_UPDATE=true` to automatically update templated golden
-//! files.
+//! Running with `GOLDIE_UPDATE=true` regenerates the template by replacing
+//! every occurrence of a context value in `actual` with the expression that
+//! renders it.
 //!
 //! ```
 //! #[test]
@@ -46,6 +47,69 @@
 //!     goldie::assert_template!(&ctx, text)
 //! }
 //! ```
+//!
+//! Volatile substrings (absolute paths, temp dirs, timestamps, ...) can be
+//! normalized away with `goldie::assert_with!`, which takes a table of
+//! `(placeholder, value)` pairs and replaces each `value` in the actual text
+//! with its `placeholder` before comparing. `[WORKSPACE]` is redacted
+//! automatically. For volatile patterns that can't be listed as a concrete
+//! value ahead of time, [`Goldie::with_builtin_redactions`] opts into a small
+//! set of built-in regexes for common cases: version numbers (`[VERSION]`),
+//! durations (`[DURATION]`) and backtrace frames (`[BACKTRACE]`).
+//!
+//! ```
+//! #[test]
+//! fn example() {
+//!     let text = { /* ... run the test, producing an absolute path ... */ };
+//!
+//!     goldie::assert_with!([("[TMP]", std::env::temp_dir().display().to_string())], text)
+//! }
+//! ```
+//!
+//! `goldie::assert_matches!` treats the golden file as a wildcard pattern
+//! instead of requiring an exact match. `[..]` matches any run of characters
+//! within a line, and a line of just `...` matches zero or more lines.
+//!
+//! ```
+//! #[test]
+//! fn example() {
+//!     let text = { /* ... run the test, producing e.g. a PID or address ... */ };
+//!
+//!     // the golden file might contain `listening on 127.0.0.1:[..]`
+//!     goldie::assert_matches!(text)
+//! }
+//! ```
+//!
+//! `goldie::assert_dir` runs a transformation over every file matching a
+//! glob and compares the result against a sibling golden file, so adding a
+//! new case is just a matter of dropping in a new input file.
+//!
+//! ```
+//! #[test]
+//! fn example() {
+//!     goldie::assert_dir("testdata/cases/*.in", |input: &str| {
+//!         /* ... run the test on `input` ... */
+//!         # input.to_owned()
+//!     })
+//! }
+//! ```
+//!
+//! `goldie::assert_json_with!` redacts the values at a list of JSON Pointer
+//! paths before comparing, for fields like generated IDs or timestamps that
+//! are not deterministic.
+//!
+//! ```
+//! #[test]
+//! fn example() {
+//!     let user = { /* ... create a user with a generated id ... */ };
+//!
+//!     goldie::assert_json_with!(user, &["/id", "/created_at"])
+//! }
+//! ```
+
+// The doc examples use `#[test] fn example()` purely to show the shape of a
+// real test function; they aren't meant to be run as doctests.
+#![allow(clippy::test_attr_in_doctest)]
 
 #[cfg(test)]
 mod tests;
@@ -61,6 +125,7 @@ use std::sync::Mutex;
 
 use anyhow::{Context, Result};
 use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 /// Assert the golden file matches.
@@ -74,6 +139,33 @@ macro_rules! assert {
     }};
 }
 
+/// Assert the golden file matches, after applying the given redactions.
+///
+/// `redactions` is anything that can be turned into an iterator of
+/// `(placeholder, value)` pairs, see [`Goldie::with_redactions`].
+#[macro_export]
+macro_rules! assert_with {
+    ($redactions:expr, $actual:expr) => {{
+        let g = $crate::_new_goldie!().with_redactions($redactions);
+        if let Err(err) = g.assert($actual) {
+            ::std::panic!("{}", err);
+        }
+    }};
+}
+
+/// Assert the golden file matches, treating it as a wildcard pattern.
+///
+/// See [`Goldie::match_patterns`].
+#[macro_export]
+macro_rules! assert_matches {
+    ($actual:expr) => {{
+        let g = $crate::_new_goldie!().match_patterns(true);
+        if let Err(err) = g.assert($actual) {
+            ::std::panic!("{}", err);
+        }
+    }};
+}
+
 /// Assert the golden file matches the debug output.
 #[macro_export]
 macro_rules! assert_debug {
@@ -107,6 +199,20 @@ macro_rules! assert_json {
     }};
 }
 
+/// Assert the JSON golden file matches, after redacting the given JSON
+/// Pointer paths.
+///
+/// See [`Goldie::assert_json_with`].
+#[macro_export]
+macro_rules! assert_json_with {
+    ($actual:expr, $redact:expr) => {{
+        let g = $crate::_new_goldie!();
+        if let Err(err) = g.assert_json_with($actual, $redact) {
+            ::std::panic!("{}", err);
+        }
+    }};
+}
+
 /// Constructs a new goldie instance.
 ///
 /// Not public API.
@@ -114,9 +220,10 @@ macro_rules! assert_json {
 #[macro_export]
 macro_rules! _new_goldie {
     () => {{
-        let source_file = $crate::cargo_workspace_dir(env!("CARGO_MANIFEST_DIR")).join(file!());
+        let workspace_dir = $crate::cargo_workspace_dir(env!("CARGO_MANIFEST_DIR"));
+        let source_file = workspace_dir.join(file!());
         let function_path = $crate::_function_path!();
-        $crate::Goldie::new(source_file, function_path)
+        $crate::Goldie::new(source_file, function_path).with_workspace_dir(workspace_dir)
     }};
 }
 
@@ -147,6 +254,14 @@ pub struct Goldie {
     golden_file: PathBuf,
     /// Whether to update the golden file if it doesn't match.
     update: bool,
+    /// An ordered table of `(placeholder, value)` pairs applied to the
+    /// actual text before comparing or updating.
+    redactions: Vec<(String, String)>,
+    /// Whether to also apply the built-in regex redactions, see
+    /// [`Goldie::with_builtin_redactions`].
+    builtin_redactions: bool,
+    /// Whether the golden file should be treated as a wildcard pattern.
+    match_patterns: bool,
 }
 
 impl Goldie {
@@ -179,26 +294,120 @@ impl Goldie {
         Self {
             golden_file,
             update,
+            redactions: Vec::new(),
+            builtin_redactions: false,
+            match_patterns: false,
         }
     }
 
+    /// Enables wildcard matching against the golden file.
+    ///
+    /// When enabled the golden file is treated as a pattern rather than
+    /// being compared byte-for-byte: `[..]` matches any run of characters
+    /// within a single line, and a line consisting solely of `...` matches
+    /// zero or more entire lines. This has no effect when `GOLDIE_UPDATE` is
+    /// set, since the (redacted) actual text is always written in that case.
+    pub fn match_patterns(mut self, yes: bool) -> Self {
+        self.match_patterns = yes;
+        self
+    }
+
+    /// Sets the workspace dir used to automatically redact `[WORKSPACE]`.
+    ///
+    /// Not public API.
+    #[doc(hidden)]
+    pub fn with_workspace_dir(mut self, workspace_dir: impl AsRef<Path>) -> Self {
+        self.redactions.push((
+            String::from("[WORKSPACE]"),
+            workspace_dir.as_ref().display().to_string(),
+        ));
+        self
+    }
+
+    /// Adds redactions that are applied to the actual text before comparing
+    /// or updating the golden file.
+    ///
+    /// Each pair is a `(placeholder, value)` e.g. `("[TMP]", tempdir)`, where
+    /// every occurrence of `value` in the actual text is replaced with
+    /// `placeholder`. Redactions are applied longest value first so that
+    /// overlapping values don't interfere with each other. This keeps golden
+    /// files portable across machines and CI where such values are not
+    /// deterministic.
+    pub fn with_redactions<K, V, I>(mut self, redactions: I) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        self.redactions
+            .extend(redactions.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Enables the built-in redactions for common volatile substrings that
+    /// can't be listed ahead of time as a concrete `(placeholder, value)`
+    /// pair: version numbers (`[VERSION]`), durations (`[DURATION]`) and
+    /// backtrace frames (`[BACKTRACE]`). Applied in addition to, and after,
+    /// any explicit `with_redactions` table.
+    pub fn with_builtin_redactions(mut self) -> Self {
+        self.builtin_redactions = true;
+        self
+    }
+
+    fn redact(&self, text: &str) -> String {
+        let mut redactions: Vec<_> = self.redactions.iter().collect();
+        redactions.sort_by_key(|(_, value)| std::cmp::Reverse(value.len()));
+
+        let mut text = text.to_owned();
+        for (placeholder, value) in redactions {
+            if !value.is_empty() {
+                text = text.replace(value.as_str(), placeholder);
+            }
+        }
+        if self.builtin_redactions {
+            for (placeholder, pattern) in builtin_redactions() {
+                text = pattern.replace_all(&text, *placeholder).into_owned();
+            }
+        }
+        text
+    }
+
     #[track_caller]
     pub fn assert(&self, actual: impl AsRef<str>) -> Result<()> {
+        let actual = self.redact(actual.as_ref());
         if self.update {
             let dir = self.golden_file.parent().unwrap();
             fs::create_dir_all(dir)?;
-            fs::write(&self.golden_file, actual.as_ref())?;
+            fs::write(&self.golden_file, &actual)?;
         } else {
             let expected = fs::read_to_string(&self.golden_file)
                 .with_context(|| self.error("failed to read golden file"))?;
-            pretty_assertions::assert_eq!(
-                actual.as_ref(),
-                expected,
-                "\n\ngolden file `{}` does not match",
-                self.golden_file
-                    .strip_prefix(env::current_dir()?)?
-                    .display(),
-            );
+
+            if self.match_patterns {
+                if !pattern_matches(&expected, &actual) {
+                    let mismatch = match first_mismatched_line(&expected, &actual) {
+                        Some(n) => format!(" (first mismatch at golden file line {n})"),
+                        None => String::new(),
+                    };
+                    pretty_assertions::assert_eq!(
+                        actual,
+                        expected,
+                        "\n\ngolden file pattern `{}` does not match{mismatch}",
+                        self.golden_file
+                            .strip_prefix(env::current_dir()?)?
+                            .display(),
+                    );
+                }
+            } else {
+                pretty_assertions::assert_eq!(
+                    actual,
+                    expected,
+                    "\n\ngolden file `{}` does not match",
+                    self.golden_file
+                        .strip_prefix(env::current_dir()?)?
+                        .display(),
+                );
+            }
         }
         Ok(())
     }
@@ -214,28 +423,83 @@ impl Goldie {
             upon::Engine::with_syntax(upon::SyntaxBuilder::new().expr("{{", "}}").build())
         });
 
-        let contents = fs::read_to_string(&self.golden_file)
-            .with_context(|| self.error("failed to read golden file"))?;
-        let expected = ENGINE
-            .compile(&contents)
-            .with_context(|| self.error("failed to compile golden file template"))?
-            .render(&ctx)
-            .with_context(|| self.error("failed to render golden file template"))?;
-
-        pretty_assertions::assert_eq!(
-            actual.as_ref(),
-            expected,
-            "\n\ngolden file `{}` does not match",
-            self.golden_file
-                .strip_prefix(env::current_dir()?)?
-                .display(),
-        );
+        let actual = actual.as_ref();
+
+        if self.update {
+            // The golden file is a template, not literal output, so it can't
+            // simply be overwritten with `actual`. Instead find every leaf
+            // value in `ctx` that appears verbatim in `actual` and replace it
+            // with the `{{ path.to.field }}` expression that renders it,
+            // longest values first so that e.g. a full name is substituted
+            // before its first name.
+            let ctx = serde_json::to_value(&ctx)?;
+            let mut leaves = Vec::new();
+            collect_template_leaves(&ctx, &mut Vec::new(), &mut leaves);
+            leaves.sort_by_key(|(_, value)| std::cmp::Reverse(value.len()));
+
+            let mut template = actual.to_owned();
+            for (path, value) in &leaves {
+                if !value.is_empty() {
+                    template = template.replace(value.as_str(), &format!("{{{{ {path} }}}}"));
+                }
+            }
+
+            let rendered = ENGINE
+                .compile(&template)
+                .with_context(|| self.error("failed to compile updated golden file template"))?
+                .render(&ctx)
+                .with_context(|| self.error("failed to render updated golden file template"))?;
+            anyhow::ensure!(
+                rendered == actual,
+                "{}",
+                self.error("could not unambiguously re-template golden file")
+            );
+
+            let dir = self.golden_file.parent().unwrap();
+            fs::create_dir_all(dir)?;
+            fs::write(&self.golden_file, &template)?;
+        } else {
+            let contents = fs::read_to_string(&self.golden_file)
+                .with_context(|| self.error("failed to read golden file"))?;
+            let expected = ENGINE
+                .compile(&contents)
+                .with_context(|| self.error("failed to compile golden file template"))?
+                .render(&ctx)
+                .with_context(|| self.error("failed to render golden file template"))?;
+
+            pretty_assertions::assert_eq!(
+                actual,
+                expected,
+                "\n\ngolden file `{}` does not match",
+                self.golden_file
+                    .strip_prefix(env::current_dir()?)?
+                    .display(),
+            );
+        }
 
         Ok(())
     }
 
     #[track_caller]
     pub fn assert_json(&self, actual: impl Serialize) -> Result<()> {
+        self.assert_json_with(actual, &[])
+    }
+
+    /// Assert the JSON golden file matches, after redacting the value at
+    /// each of the given [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901)
+    /// JSON Pointer paths (e.g. `/user/id`).
+    ///
+    /// A `*` path segment matches every element of an array or object, e.g.
+    /// `/items/*/created_at`. Redacted values are replaced with the
+    /// sentinel string `"[redacted]"` in both the actual and expected JSON
+    /// before comparing, and in the golden file when updating.
+    #[track_caller]
+    pub fn assert_json_with(&self, actual: impl Serialize, redact: &[&str]) -> Result<()> {
+        let mut actual: serde_json::Value = serde_json::to_value(&actual)?;
+        for pointer in redact {
+            redact_json_pointer(&mut actual, pointer);
+        }
+
         if self.update {
             let dir = self.golden_file.parent().unwrap();
             fs::create_dir_all(dir)?;
@@ -246,9 +510,11 @@ impl Goldie {
         } else {
             let contents = fs::read_to_string(&self.golden_file)
                 .with_context(|| self.error("failed to read golden file"))?;
-            let expected: serde_json::Value =
+            let mut expected: serde_json::Value =
                 serde_json::from_str(&contents).with_context(|| self.error("bad JSON"))?;
-            let actual: serde_json::Value = serde_json::to_value(&actual)?;
+            for pointer in redact {
+                redact_json_pointer(&mut expected, pointer);
+            }
 
             pretty_assertions::assert_eq!(
                 actual,
@@ -274,6 +540,300 @@ impl Goldie {
     }
 }
 
+/// Returns the built-in `(placeholder, pattern)` redactions enabled by
+/// [`Goldie::with_builtin_redactions`], for volatile substrings whose
+/// concrete value can't be known ahead of time (unlike `[WORKSPACE]`, which
+/// is looked up directly). Each pattern is deliberately conservative about
+/// what it matches, to avoid mangling golden content that merely looks
+/// similar, e.g. an IP address or a numbered list.
+fn builtin_redactions() -> &'static [(&'static str, Regex)] {
+    static REDACTIONS: Lazy<Vec<(&'static str, Regex)>> = Lazy::new(|| {
+        vec![
+            (
+                // Requires a `v` prefix or `-`/`+` metadata to avoid matching
+                // plain dotted-number sequences like IP addresses.
+                "[VERSION]",
+                Regex::new(
+                    r"\bv\d+\.\d+\.\d+(?:[-+][0-9A-Za-z.]+)?\b|\b\d+\.\d+\.\d+[-+][0-9A-Za-z.]+\b",
+                )
+                .unwrap(),
+            ),
+            (
+                "[DURATION]",
+                Regex::new(r"\b\d+(?:\.\d+)?(?:ns|µs|us|ms|s|m|h)\b").unwrap(),
+            ),
+            (
+                // Requires a `::` module path to avoid matching other
+                // numbered lines, e.g. a numbered list or an HTTP status.
+                "[BACKTRACE]",
+                Regex::new(r"(?m)^\s*\d+:\s+\S*::\S*.*$").unwrap(),
+            ),
+        ]
+    });
+    &REDACTIONS
+}
+
+/// Returns whether `actual` matches the `expected` pattern.
+///
+/// `[..]` matches any run of characters within a single line, and a line
+/// consisting solely of `...` matches zero or more entire lines.
+fn pattern_matches(expected: &str, actual: &str) -> bool {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    pattern_matches_lines(&expected, &actual)
+}
+
+fn pattern_matches_lines(expected: &[&str], actual: &[&str]) -> bool {
+    match expected.first() {
+        None => actual.is_empty(),
+        Some(&"...") => (0..=actual.len())
+            .any(|n| pattern_matches_lines(&expected[1..], &actual[n..])),
+        Some(pattern) => {
+            matches!(actual.first(), Some(line) if pattern_matches_line(pattern, line))
+                && pattern_matches_lines(&expected[1..], &actual[1..])
+        }
+    }
+}
+
+/// Returns whether `line` matches the `pattern`, where `[..]` in `pattern`
+/// matches any run of characters.
+fn pattern_matches_line(pattern: &str, line: &str) -> bool {
+    if !pattern.contains("[..]") {
+        return pattern == line;
+    }
+
+    let segments: Vec<&str> = pattern.split("[..]").collect();
+    let last = segments.len() - 1;
+    let mut rest = line;
+
+    for (i, segment) in segments.iter().enumerate() {
+        if i == 0 {
+            match rest.strip_prefix(segment) {
+                Some(r) => rest = r,
+                None => return false,
+            }
+        } else if i == last {
+            return rest.ends_with(segment);
+        } else if !segment.is_empty() {
+            match rest.find(segment) {
+                Some(pos) => rest = &rest[pos + segment.len()..],
+                None => return false,
+            }
+        }
+    }
+
+    true
+}
+
+/// Returns the 1-based line number of the first line in `expected` that
+/// doesn't match the line at the same position in `actual`, ignoring `...`
+/// wildcard lines. This is only used to give a more specific diagnostic on
+/// failure; it doesn't need to account for how `...` can shift later lines,
+/// since the full pattern and actual text are still shown alongside it.
+fn first_mismatched_line(expected: &str, actual: &str) -> Option<usize> {
+    expected
+        .lines()
+        .zip(actual.lines())
+        .position(|(pattern, line)| pattern != "..." && !pattern_matches_line(pattern, line))
+        .map(|i| i + 1)
+}
+
+/// Collects `(path, value)` pairs for every leaf scalar in `ctx`, where
+/// `path` is the dotted path to that leaf e.g. `user.name`.
+fn collect_template_leaves(
+    ctx: &serde_json::Value,
+    path: &mut Vec<String>,
+    leaves: &mut Vec<(String, String)>,
+) {
+    match ctx {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                path.push(key.clone());
+                collect_template_leaves(value, path, leaves);
+                path.pop();
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for (i, value) in arr.iter().enumerate() {
+                path.push(i.to_string());
+                collect_template_leaves(value, path, leaves);
+                path.pop();
+            }
+        }
+        serde_json::Value::Null => {}
+        scalar if !path.is_empty() => {
+            let value = match scalar {
+                serde_json::Value::String(s) => s.clone(),
+                _ => scalar.to_string(),
+            };
+            leaves.push((path.join("."), value));
+        }
+        _ => {}
+    }
+}
+
+/// Replaces the value at `pointer` in `value` with the sentinel string
+/// `"[redacted]"`, where a `*` path segment matches every element of an
+/// array or object.
+fn redact_json_pointer(value: &mut serde_json::Value, pointer: &str) {
+    let segments: Vec<&str> = match pointer.strip_prefix('/') {
+        Some(rest) if !rest.is_empty() => rest.split('/').collect(),
+        _ => return,
+    };
+    redact_json_segments(value, &segments);
+}
+
+fn redact_json_segments(value: &mut serde_json::Value, segments: &[&str]) {
+    let Some((head, tail)) = segments.split_first() else {
+        *value = serde_json::Value::String(String::from("[redacted]"));
+        return;
+    };
+
+    match value {
+        serde_json::Value::Object(map) => {
+            if *head == "*" {
+                for v in map.values_mut() {
+                    redact_json_segments(v, tail);
+                }
+            } else if let Some(v) = map.get_mut(&unescape_json_pointer_segment(head)) {
+                redact_json_segments(v, tail);
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            if *head == "*" {
+                for v in arr.iter_mut() {
+                    redact_json_segments(v, tail);
+                }
+            } else if let Some(v) = head.parse::<usize>().ok().and_then(|i| arr.get_mut(i)) {
+                redact_json_segments(v, tail);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Decodes a JSON Pointer path segment per RFC 6901 (`~1` -> `/`, `~0` -> `~`).
+fn unescape_json_pointer_segment(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+/// Runs `transform` over every file matching `glob` and asserts the result
+/// against a sibling golden file.
+///
+/// `glob` is a path relative to the current directory (which `cargo test`
+/// sets to the package root), e.g. `testdata/cases/*.in`, and may contain
+/// `*` wildcards in the filename. For each matching file `foo.in` the
+/// golden file `foo.golden` in the same directory is compared against
+/// `transform`'s output. All cases are run even if earlier ones fail, and
+/// with `GOLDIE_UPDATE` every golden in the set is written, creating missing
+/// ones.
+#[track_caller]
+pub fn assert_dir(glob: &str, transform: impl Fn(&str) -> String) {
+    let update = matches!(
+        env::var("GOLDIE_UPDATE").ok().as_deref(),
+        Some("1" | "true")
+    );
+    if let Err(err) = assert_dir_impl(glob, &transform, update) {
+        std::panic!("{err}");
+    }
+}
+
+fn assert_dir_impl(glob: &str, transform: &impl Fn(&str) -> String, update: bool) -> Result<()> {
+    let inputs = glob_files(glob)?;
+    anyhow::ensure!(!inputs.is_empty(), "no files matched glob `{glob}`");
+
+    let mut failures = Vec::new();
+    for input in inputs {
+        let golden = input.with_extension("golden");
+        let actual = transform(
+            &fs::read_to_string(&input)
+                .with_context(|| format!("failed to read input file `{}`", input.display()))?,
+        );
+
+        if update {
+            fs::write(&golden, &actual)
+                .with_context(|| format!("failed to write golden file `{}`", golden.display()))?;
+            continue;
+        }
+
+        match fs::read_to_string(&golden) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => failures.push(format!(
+                "case `{}` does not match golden file `{}`\n\n{}",
+                input.display(),
+                golden.display(),
+                pretty_assertions::Comparison::new(&actual, &expected),
+            )),
+            Err(_) => failures.push(format!(
+                "case `{}` is missing golden file `{}`, run with {} to generate it",
+                input.display(),
+                golden.display(),
+                "GOLDIE_UPDATE=1",
+            )),
+        }
+    }
+
+    if !failures.is_empty() {
+        anyhow::bail!("\n\n{}\n", failures.join("\n\n"));
+    }
+
+    Ok(())
+}
+
+/// Returns every file in `glob`'s directory whose name matches `glob`'s
+/// filename pattern, which may contain `*` wildcards.
+fn glob_files(glob: &str) -> Result<Vec<PathBuf>> {
+    let glob = Path::new(glob);
+    let dir = glob.parent().filter(|p| !p.as_os_str().is_empty());
+    let pattern = glob
+        .file_name()
+        .and_then(OsStr::to_str)
+        .with_context(|| format!("invalid glob `{}`", glob.display()))?;
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir.unwrap_or_else(|| Path::new(".")))? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(OsStr::to_str) else {
+            continue;
+        };
+        if glob_matches(pattern, name) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Returns whether `name` matches `pattern`, where `*` matches any run of
+/// characters.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    if !pattern.contains('*') {
+        return pattern == name;
+    }
+
+    let segments: Vec<&str> = pattern.split('*').collect();
+    let last = segments.len() - 1;
+    let mut rest = name;
+
+    for (i, segment) in segments.iter().enumerate() {
+        if i == 0 {
+            match rest.strip_prefix(segment) {
+                Some(r) => rest = r,
+                None => return false,
+            }
+        } else if i == last {
+            return rest.ends_with(segment);
+        } else if !segment.is_empty() {
+            match rest.find(segment) {
+                Some(pos) => rest = &rest[pos + segment.len()..],
+                None => return false,
+            }
+        }
+    }
+
+    true
+}
+
 /// Returns the Cargo workspace dir for the given manifest dir.
 ///
 /// Not public API.
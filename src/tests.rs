@@ -65,6 +65,324 @@ fn goldie_assert_template() {
     crate::assert_template!(&ctx, "Such testing...\n");
 }
 
+#[test]
+fn goldie_assert_with() {
+    crate::assert_with!([("[NAME]", "Steve")], "Hello Steve!\n");
+}
+
+#[test]
+fn goldie_assert_template_update() {
+    #[derive(Serialize)]
+    struct Context {
+        name: &'static str,
+        day: &'static str,
+    }
+    let ctx = Context {
+        name: "Steve",
+        day: "Monday",
+    };
+    let actual = "Hello Steve, see you Monday!\n";
+
+    let dir = temp_dir("template-update");
+    let mut g = Goldie::new(
+        "/repo/src/lib.rs",
+        "crate::tests::goldie_assert_template_update",
+    );
+    g.golden_file = dir.join("out.golden");
+    g.update = true;
+
+    g.assert_template(&ctx, actual).unwrap();
+
+    let template = fs::read_to_string(&g.golden_file).unwrap();
+    std::assert!(template.contains("{{ name }}"), "{template}");
+    std::assert!(template.contains("{{ day }}"), "{template}");
+
+    // a subsequent non-update render round-trips against `actual`.
+    g.update = false;
+    g.assert_template(&ctx, actual).unwrap();
+}
+
+#[test]
+fn goldie_assert_template_update_ensure_failure() {
+    #[derive(Serialize)]
+    struct Context {
+        price: f64,
+    }
+    // upon renders a whole-number f64 without a trailing `.0`, but
+    // `serde_json::Value`'s `Display` keeps it, so substituting this value
+    // back out of `actual` and re-rendering the template doesn't round-trip.
+    let ctx = Context { price: 100.0 };
+    let actual = "Price: $100.0\n";
+
+    let dir = temp_dir("template-update-failure");
+    let mut g = Goldie::new(
+        "/repo/src/lib.rs",
+        "crate::tests::goldie_assert_template_update_ensure_failure",
+    );
+    g.golden_file = dir.join("out.golden");
+    g.update = true;
+
+    let err = g.assert_template(&ctx, actual).unwrap_err();
+    std::assert!(
+        err.to_string()
+            .contains("could not unambiguously re-template golden file"),
+        "{err}"
+    );
+}
+
+#[test]
+fn goldie_redact() {
+    let g = Goldie::new("/repo/src/lib.rs", "crate::tests::func")
+        .with_redactions([("[NAME]", "Steve"), ("[FULL_NAME]", "Steve Harrington")]);
+
+    assert_eq!(
+        g.redact("Hello Steve Harrington, hello Steve!"),
+        "Hello [FULL_NAME], hello [NAME]!"
+    );
+}
+
+#[test]
+fn goldie_assert_matches() {
+    crate::assert_matches!("listening on 127.0.0.1:8080\n");
+}
+
+#[test]
+fn goldie_redact_builtin() {
+    let g = Goldie::new("/repo/src/lib.rs", "crate::tests::func").with_builtin_redactions();
+
+    assert_eq!(
+        g.redact("built goldie v1.2.3 in 450ms\n  0: goldie::assert\n  1: main\n"),
+        "built goldie [VERSION] in [DURATION]\n[BACKTRACE]\n  1: main\n"
+    );
+
+    // plain dotted numbers (e.g. an IP address) are left alone, since they
+    // don't have a `v` prefix or any semver pre-release/build metadata.
+    assert_eq!(
+        g.redact("listening on 192.168.1.100\n"),
+        "listening on 192.168.1.100\n"
+    );
+}
+
+#[test]
+fn goldie_assert_builtin_redactions() {
+    let g = crate::_new_goldie!().with_builtin_redactions();
+    g.assert("built goldie v1.2.3 in 450ms\n  0: goldie::assert\n  1: main\n")
+        .unwrap();
+}
+
+#[test]
+fn goldie_pattern_matches() {
+    let tests = [
+        ("testing...\n", "testing...\n", true),
+        ("listening on [..]\n", "listening on 127.0.0.1:8080\n", true),
+        ("listening on [..]\n", "not listening\n", false),
+        ("built [..] in [..]s\n", "built target in 1.2s\n", true),
+        ("first\n...\nlast\n", "first\nmiddle 1\nmiddle 2\nlast\n", true),
+        ("first\n...\nlast\n", "first\nlast\n", true),
+        ("first\n...\nlast\n", "first\nmiddle\n", false),
+    ];
+
+    for (pattern, actual, expected) in tests {
+        assert_eq!(super::pattern_matches(pattern, actual), expected);
+    }
+}
+
+#[test]
+fn goldie_first_mismatched_line() {
+    let tests = [
+        ("testing...\n", "testing...\n", None),
+        ("listening on [..]\n", "listening on 127.0.0.1:8080\n", None),
+        ("listening on [..]\n", "not listening\n", Some(1)),
+        ("first\nsecond\nthird\n", "first\nbad\nthird\n", Some(2)),
+        ("first\n...\nlast\n", "first\nmiddle\nlast\n", None),
+    ];
+
+    for (pattern, actual, expected) in tests {
+        assert_eq!(super::first_mismatched_line(pattern, actual), expected);
+    }
+}
+
+/// Returns a fresh temp directory dedicated to the named test, so parallel
+/// test runs don't trample each other's fixtures.
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("goldie-assert-dir-test-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn goldie_assert_dir() {
+    let dir = temp_dir("pass");
+    fs::write(dir.join("case-1.in"), "one\n").unwrap();
+    fs::write(dir.join("case-1.golden"), "ONE\n").unwrap();
+    fs::write(dir.join("case-2.in"), "two\n").unwrap();
+    fs::write(dir.join("case-2.golden"), "TWO\n").unwrap();
+
+    let glob = format!("{}/case-*.in", dir.display());
+    super::assert_dir(&glob, |input: &str| input.to_uppercase());
+}
+
+#[test]
+fn goldie_assert_dir_impl_update() {
+    let dir = temp_dir("update");
+    fs::write(dir.join("case-1.in"), "one\n").unwrap();
+
+    let glob = format!("{}/case-*.in", dir.display());
+    let transform = |input: &str| input.to_uppercase();
+    super::assert_dir_impl(&glob, &transform, true).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(dir.join("case-1.golden")).unwrap(),
+        "ONE\n"
+    );
+
+    // a subsequent non-update run matches what was just written.
+    super::assert_dir_impl(&glob, &transform, false).unwrap();
+}
+
+#[test]
+fn goldie_assert_dir_impl_failures() {
+    let dir = temp_dir("failures");
+    fs::write(dir.join("case-1.in"), "one\n").unwrap();
+    fs::write(dir.join("case-1.golden"), "WRONG\n").unwrap();
+    fs::write(dir.join("case-2.in"), "two\n").unwrap();
+    // case-2 is missing its golden file entirely.
+
+    let glob = format!("{}/case-*.in", dir.display());
+    let transform = |input: &str| input.to_uppercase();
+    let err = super::assert_dir_impl(&glob, &transform, false).unwrap_err();
+
+    let msg = err.to_string();
+    std::assert!(msg.contains("case-1.in"), "{msg}");
+    std::assert!(msg.contains("case-2.in"), "{msg}");
+}
+
+#[test]
+fn goldie_assert_dir_impl_empty_glob() {
+    let dir = temp_dir("empty-glob");
+    // case-1.in exists, but the glob below has a typo'd extension.
+    fs::write(dir.join("case-1.in"), "one\n").unwrap();
+
+    let glob = format!("{}/case-*.inn", dir.display());
+    let transform = |input: &str| input.to_uppercase();
+    let err = super::assert_dir_impl(&glob, &transform, false).unwrap_err();
+
+    std::assert!(err.to_string().contains("no files matched glob"), "{err}");
+}
+
+#[test]
+fn goldie_glob_matches() {
+    let tests = [
+        ("foo.in", "foo.in", true),
+        ("*.in", "foo.in", true),
+        ("*.in", "foo.golden", false),
+        ("case-*.in", "case-1.in", true),
+        ("case-*.in", "other-1.in", false),
+    ];
+
+    for (pattern, name, expected) in tests {
+        assert_eq!(super::glob_matches(pattern, name), expected);
+    }
+}
+
+#[test]
+fn goldie_collect_template_leaves() {
+    let ctx = serde_json::json!({
+        "value": "Hello World!",
+        "user": { "name": "Steve" },
+        "tags": ["a", "b"],
+    });
+
+    let mut leaves = Vec::new();
+    super::collect_template_leaves(&ctx, &mut Vec::new(), &mut leaves);
+    leaves.sort();
+
+    assert_eq!(
+        leaves,
+        [
+            (String::from("tags.0"), String::from("a")),
+            (String::from("tags.1"), String::from("b")),
+            (String::from("user.name"), String::from("Steve")),
+            (String::from("value"), String::from("Hello World!")),
+        ]
+    );
+}
+
+#[test]
+fn goldie_redact_json_pointer() {
+    let mut value = serde_json::json!({
+        "id": 1,
+        "name": "Steve",
+        "items": [
+            { "id": "a", "created_at": "2021-01-01" },
+            { "id": "b", "created_at": "2021-01-02" },
+        ],
+    });
+
+    super::redact_json_pointer(&mut value, "/id");
+    super::redact_json_pointer(&mut value, "/items/*/created_at");
+
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "id": "[redacted]",
+            "name": "Steve",
+            "items": [
+                { "id": "a", "created_at": "[redacted]" },
+                { "id": "b", "created_at": "[redacted]" },
+            ],
+        })
+    );
+}
+
+#[test]
+fn goldie_assert_json_with() {
+    #[derive(Serialize)]
+    struct User {
+        id: u32,
+        name: &'static str,
+    }
+
+    let u = User {
+        id: 42,
+        name: "Steve",
+    };
+
+    crate::assert_json_with!(&u, &["/id"]);
+}
+
+#[test]
+fn goldie_assert_json_with_update() {
+    #[derive(Serialize)]
+    struct User {
+        id: u32,
+        name: &'static str,
+    }
+
+    let u = User {
+        id: 42,
+        name: "Steve",
+    };
+
+    let dir = temp_dir("json-with-update");
+    let mut g = Goldie::new(
+        "/repo/src/lib.rs",
+        "crate::tests::goldie_assert_json_with_update",
+    );
+    g.golden_file = dir.join("out.golden");
+    g.update = true;
+
+    g.assert_json_with(&u, &["/id"]).unwrap();
+    let written = fs::read_to_string(&g.golden_file).unwrap();
+    std::assert!(written.contains("\"[redacted]\""), "{written}");
+    std::assert!(written.contains("\"Steve\""), "{written}");
+
+    // a subsequent non-update call matches what was just written.
+    g.update = false;
+    g.assert_json_with(&u, &["/id"]).unwrap();
+}
+
 #[test]
 fn goldie_assert_json() {
     #[derive(Serialize)]